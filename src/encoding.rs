@@ -0,0 +1,96 @@
+//! 非UTF-8位元組輸入（Big5／GBK／GB18030等）的編碼偵測與轉換，供`sort_zh_bytes`使用。
+
+use std::fmt;
+
+use encoding_rs::{Encoding, BIG5, EUC_KR, GB18030, SHIFT_JIS, UTF_8};
+
+/// 呼叫端指定了`encoding_override`，但其值不是任何已知編碼（或別名）。
+#[derive(Debug)]
+pub(crate) struct UnknownEncodingError(String);
+
+impl fmt::Display for UnknownEncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown encoding override: \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for UnknownEncodingError {}
+
+/// 偵測（UTF-8判定失敗後）嘗試的候選合法編碼。
+const LEGACY_CANDIDATE_ENCODINGS: [&Encoding; 4] = [BIG5, GB18030, EUC_KR, SHIFT_JIS];
+
+/// 依名稱（含WHATWG標準別名，例如`"GBK"`、`"gb2312"`）尋找編碼，找不到時回傳`None`。
+fn encoding_by_name(name: &str) -> Option<&'static Encoding> {
+    Encoding::for_label(name.as_bytes())
+}
+
+/// 為候選編碼對這段位元組的合適程度計分：解碼時出現無法映射的位元組直接淘汰，
+/// 其餘依實際解出的非ASCII字元數量評分，藉此偏好真正「用到」該編碼特性的候選。
+fn score(encoding: &'static Encoding, bytes: &[u8]) -> i64 {
+    let (decoded, _, had_errors) = encoding.decode(bytes);
+    if had_errors {
+        return i64::MIN;
+    }
+    decoded.chars().filter(|char| !char.is_ascii()).count() as i64
+}
+
+/// 偵測位元組陣列最可能的編碼：合法UTF-8一律優先採用（否則任何2-byte legacy編碼都會因為
+/// 「每個byte能多湊出一個字元」而在計分上系統性地贏過正確的UTF-8），其餘才在legacy候選中
+/// 依[`score`]評分，全部候選都無法合法解碼時退回UTF-8。
+fn detect_encoding(bytes: &[u8]) -> &'static Encoding {
+    if std::str::from_utf8(bytes).is_ok() {
+        return UTF_8;
+    }
+    LEGACY_CANDIDATE_ENCODINGS
+        .into_iter()
+        .map(|encoding| (encoding, score(encoding, bytes)))
+        .filter(|(_, score)| *score != i64::MIN)
+        .max_by_key(|(_, score)| *score)
+        .map_or(UTF_8, |(encoding, _)| encoding)
+}
+
+/// 將位元組解碼為UTF-8字串。`override_encoding`非空時直接採用（略過偵測，名稱不明時回傳錯誤），
+/// 否則呼叫[`detect_encoding`]自動判斷。回傳解碼後字串與實際採用的編碼，供日後重新編碼使用。
+pub(crate) fn decode(
+    bytes: &[u8],
+    override_encoding: Option<&str>,
+) -> Result<(String, &'static Encoding), UnknownEncodingError> {
+    let encoding = match override_encoding {
+        Some(name) => {
+            encoding_by_name(name).ok_or_else(|| UnknownEncodingError(name.to_string()))?
+        }
+        None => detect_encoding(bytes),
+    };
+    let (decoded, _, _) = encoding.decode(bytes);
+    Ok((decoded.into_owned(), encoding))
+}
+
+/// 將字串以指定編碼重新編碼回位元組。
+pub(crate) fn encode(string: &str, encoding: &'static Encoding) -> Vec<u8> {
+    encoding.encode(string).0.into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8_cjk_roundtrips_without_mojibake() {
+        let original = "中文";
+        let (decoded, encoding) = decode(original.as_bytes(), None).unwrap();
+        assert_eq!(decoded, original);
+        assert_eq!(encoding, UTF_8);
+        assert_eq!(encode(&decoded, encoding), original.as_bytes());
+    }
+
+    #[test]
+    fn override_accepts_gbk_label() {
+        let (_, encoding) = decode("中文".as_bytes(), Some("GBK")).unwrap();
+        assert_eq!(encoding, encoding_rs::GBK);
+    }
+
+    #[test]
+    fn unknown_override_is_an_error() {
+        assert!(decode(b"abc", Some("not-a-real-encoding")).is_err());
+    }
+}
@@ -5,8 +5,14 @@
 //! 在Rust中，如果直接使用`sort()`系列function進行Vec的排序，非ASCII部分的文字會因為Unicode Hex Code的排序而混亂。
 //!
 //! 本crate提供了`sort_zh()` function 進行正確的排序（預設透過筆畫順序），用戶也可以利用`SortZhOptions`中的設定進行自定義排序。
+//!
+//! 若不透過wasm／JS使用，也可以直接呼叫原生Rust API `sort_zh_with()`（或`sort_zh_vec()`），兩者都會完整套用傳入的`SortZhOptions`。
+
+mod encoding;
+mod normalize;
+mod pinyin;
 
-use crate::{ChineseVariant::*, UpperCaseOrder::*, ZhNumberOption::*};
+use crate::{UpperCaseOrder::*, ZhNumberOption::*};
 use chinese_number::{
     parse_chinese_number_to_i64, ChineseNumberCountMethod, ChineseNumberParseError,
 };
@@ -14,21 +20,60 @@ use js_sys::JsString;
 use rust_icu_ucol::UCollator;
 use std::str::Chars;
 use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
 
 /// 排序選項
 pub struct SortZhOptions {
-    /// 繁體中文/簡體中文（預設為繁體中文）
+    /// 排序使用的ICU locale（預設為`"zh-TW"`）
     ///
-    /// - 繁體中文使用ICU提供的中文台灣（zh_TW）Collate
-    ///
-    /// - 簡體中文使用ICU提供的中文中國（zh_CN）Collate
-    pub variant: ChineseVariant,
+    /// 除了`"zh-TW"`（繁體中文）、`"zh-CN"`（簡體中文），也可以指定`"zh-HK"`（香港）、
+    /// `"zh-SG"`（新加坡）等ICU支援的locale，取得不同的筆畫/部首排序結果。不是已知語言代碼開頭
+    /// 的locale字串（例如typo）會讓[`sort_zh_with`]回傳[`CollatorError`]，而不會嘗試建立collator。
+    pub locale: String,
     /// 中文數字選項（預設為透過筆畫排序）
     pub zh_number_option: ZhNumberOption,
+    /// 中文字排序策略（預設為透過ICU Collator排序）
+    pub sort_strategy: SortStrategy,
+    /// 排序前的正規化方式（預設不進行正規化）
+    pub normalization: Normalization,
+    /// ICU Collator的排序強度（預設為tertiary，完整比較大小寫與腔調差異）
+    pub strength: CollationStrength,
 }
 
-/// 中文數字選項
+/// 排序前的字串正規化方式
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+pub enum Normalization {
+    /// 不進行正規化（預設）
+    #[default]
+    None,
+    /// 先進行大小寫摺疊，再進行NFKD相容性正規化後排序
+    ///
+    /// 例如`"Apple"`與`"apple"`、`"㎒"`與`"MHz"`會被視為相同排序鍵值。
+    CaseFoldNfkd,
+}
+
+/// 中文字排序策略
 #[derive(Default, PartialEq, Eq)]
+pub enum SortStrategy {
+    /// 透過ICU Collator排序（筆畫/部首順序）
+    #[default]
+    Collator,
+    /// 透過拼音排序（字典序），非中文字元排於中文字元之前
+    Pinyin(ToneHandling),
+}
+
+/// 拼音排序時的聲調處理方式
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+pub enum ToneHandling {
+    /// 忽略聲調差異，僅比較拼音字母
+    #[default]
+    Ignore,
+    /// 先比較去除聲調的拼音，再比較聲調
+    ByTone,
+}
+
+/// 中文數字選項
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
 pub enum ZhNumberOption {
     /// 透過ICU預設方式排序
     #[default]
@@ -40,7 +85,7 @@ pub enum ZhNumberOption {
 }
 
 /// 大寫數字排序選項
-#[derive(Eq, PartialEq, Default)]
+#[derive(Eq, PartialEq, Default, Clone, Copy)]
 pub enum UpperCaseOrder {
     /// 大寫數字排於小寫數字之前（例：`["壹", "貳", "一", "二"]`）
     Before,
@@ -49,20 +94,149 @@ pub enum UpperCaseOrder {
     After,
 }
 
-/// 中文字類型
-#[derive(Eq, PartialEq)]
-pub enum ChineseVariant {
-    /// 繁體中文
-    Traditional,
-    /// 簡體中文
-    Simplified,
+/// ICU Collator的排序強度
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+pub enum CollationStrength {
+    /// 只比較基本字母，忽略大小寫與腔調差異
+    Primary,
+    /// 在primary基礎上額外比較腔調差異，仍忽略大小寫
+    Secondary,
+    /// 完整比較大小寫、腔調等差異（預設）
+    #[default]
+    Tertiary,
+}
+
+impl From<CollationStrength> for rust_icu_sys::UColAttributeValue {
+    fn from(strength: CollationStrength) -> Self {
+        match strength {
+            CollationStrength::Primary => Self::UCOL_PRIMARY,
+            CollationStrength::Secondary => Self::UCOL_SECONDARY,
+            CollationStrength::Tertiary => Self::UCOL_TERTIARY,
+        }
+    }
 }
 
 impl Default for SortZhOptions {
     fn default() -> Self {
         Self {
-            variant: Traditional,
+            locale: String::from("zh-TW"),
             zh_number_option: ZhNumberOption::default(),
+            sort_strategy: SortStrategy::default(),
+            normalization: Normalization::default(),
+            strength: CollationStrength::default(),
+        }
+    }
+}
+
+/// 供wasm/JS端使用的`SortZhOptions`簡化表示。
+///
+/// `wasm_bindgen`匯出的型別僅能使用其支援的簡單欄位，無法直接對應`SortZhOptions`中帶有資料的
+/// enum（如`ZhNumberOption::DefinitionWithUpperCase`），因此改以純量欄位表示，在呼叫
+/// [`sort_zh_with`]前再轉換為完整的`SortZhOptions`。
+#[wasm_bindgen]
+pub struct JsSortZhOptions {
+    locale: String,
+    /// `0` = ICUDefault，`1` = Definition，其餘 = DefinitionWithUpperCase
+    zh_number_mode: u8,
+    upper_case_before: bool,
+    pinyin: bool,
+    pinyin_by_tone: bool,
+    normalize: bool,
+    /// `0` = Primary，`1` = Secondary，其餘 = Tertiary
+    strength: u8,
+}
+
+#[wasm_bindgen]
+impl JsSortZhOptions {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_locale(&mut self, locale: String) {
+        self.locale = locale;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_zh_number_mode(&mut self, zh_number_mode: u8) {
+        self.zh_number_mode = zh_number_mode;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_upper_case_before(&mut self, upper_case_before: bool) {
+        self.upper_case_before = upper_case_before;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_pinyin(&mut self, pinyin: bool) {
+        self.pinyin = pinyin;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_pinyin_by_tone(&mut self, pinyin_by_tone: bool) {
+        self.pinyin_by_tone = pinyin_by_tone;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_normalize(&mut self, normalize: bool) {
+        self.normalize = normalize;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_strength(&mut self, strength: u8) {
+        self.strength = strength;
+    }
+}
+
+impl Default for JsSortZhOptions {
+    /// 預設值與舊版`sort_zh`相同：`"zh-TW"`、`DefinitionWithUpperCase(After)`、
+    /// ICU Collator排序、tertiary強度、不進行正規化。
+    fn default() -> Self {
+        Self {
+            locale: String::from("zh-TW"),
+            zh_number_mode: 2,
+            upper_case_before: false,
+            pinyin: false,
+            pinyin_by_tone: false,
+            normalize: false,
+            strength: 2,
+        }
+    }
+}
+
+impl From<JsSortZhOptions> for SortZhOptions {
+    fn from(options: JsSortZhOptions) -> Self {
+        Self {
+            locale: options.locale,
+            zh_number_option: match options.zh_number_mode {
+                0 => ZhNumberOption::ICUDefault,
+                1 => ZhNumberOption::Definition,
+                _ => ZhNumberOption::DefinitionWithUpperCase(if options.upper_case_before {
+                    UpperCaseOrder::Before
+                } else {
+                    UpperCaseOrder::After
+                }),
+            },
+            sort_strategy: if options.pinyin {
+                SortStrategy::Pinyin(if options.pinyin_by_tone {
+                    ToneHandling::ByTone
+                } else {
+                    ToneHandling::Ignore
+                })
+            } else {
+                SortStrategy::Collator
+            },
+            normalization: if options.normalize {
+                Normalization::CaseFoldNfkd
+            } else {
+                Normalization::None
+            },
+            strength: match options.strength {
+                0 => CollationStrength::Primary,
+                1 => CollationStrength::Secondary,
+                _ => CollationStrength::Tertiary,
+            },
         }
     }
 }
@@ -82,49 +256,172 @@ static UPPERCASE_NUM: [char; 50] = [
 ];
 
 #[wasm_bindgen]
-pub fn sort_zh(input: Vec<JsString>) -> Vec<JsString> {
-    let options = SortZhOptions {
-        variant: ChineseVariant::Traditional,
-        zh_number_option: ZhNumberOption::DefinitionWithUpperCase(UpperCaseOrder::After),
-    };
+pub fn sort_zh(
+    input: Vec<JsString>,
+    options: Option<JsSortZhOptions>,
+) -> Result<Vec<JsString>, JsValue> {
+    let options: SortZhOptions = options.unwrap_or_default().into();
+    let mut strings: Vec<String> = input.iter().map(Into::into).collect();
+
+    sort_zh_with(&mut strings, &options).map_err(|err| JsValue::from_str(&err.to_string()))?;
 
-    let collator = match options.variant {
-        Traditional => UCollator::try_from("zh-TW"),
-        Simplified => UCollator::try_from("zh-CN"),
+    Ok(strings.into_iter().map(JsString::from).collect())
+}
+
+/// 將位元組陣列視為中文文字排序：先偵測（或依`encoding_override`使用指定的）編碼解碼為UTF-8，
+/// 排序後再以各自原本的編碼重新編碼，讓呼叫端不需自行處理Big5/GBK等非UTF-8輸入。
+///
+/// `encoding_override`指定了無法辨識的編碼名稱時回傳錯誤，而非靜默改回自動偵測。
+#[wasm_bindgen]
+pub fn sort_zh_bytes(
+    input: Vec<Vec<u8>>,
+    options: Option<JsSortZhOptions>,
+    encoding_override: Option<String>,
+) -> Result<Vec<Vec<u8>>, JsValue> {
+    let options: SortZhOptions = options.unwrap_or_default().into();
+
+    let mut decoded: Vec<DecodedBytes> = input
+        .iter()
+        .map(|bytes| {
+            let (string, encoding) = encoding::decode(bytes, encoding_override.as_deref())
+                .map_err(|err| JsValue::from_str(&err.to_string()))?;
+            Ok(DecodedBytes { string, encoding })
+        })
+        .collect::<Result<_, JsValue>>()?;
+
+    sort_zh_with(&mut decoded, &options).map_err(|err| JsValue::from_str(&err.to_string()))?;
+
+    Ok(decoded
+        .into_iter()
+        .map(|decoded| encoding::encode(&decoded.string, decoded.encoding))
+        .collect())
+}
+
+/// `sort_zh_bytes`用來攜帶每個元素解碼結果與原始編碼的暫存型別，排序時只依`string`比較。
+#[derive(Clone)]
+struct DecodedBytes {
+    string: String,
+    encoding: &'static encoding_rs::Encoding,
+}
+
+impl AsRef<str> for DecodedBytes {
+    fn as_ref(&self) -> &str {
+        &self.string
+    }
+}
+
+/// 依照`options`原地排序，支援任何可借用為`&str`的型別（例如`String`、`&str`）。
+///
+/// 這是`sort_zh`背後實際使用的核心實作，讓非wasm的Rust使用者不需經過`JsString`／`JsValue`，
+/// 也能完整使用`SortZhOptions`的每個欄位；此crate本身仍無條件依賴`js-sys`與`wasm-bindgen`。`options.locale`不是ICU認得的locale時回傳
+/// [`CollatorError`]，而非panic。
+pub fn sort_zh_with<T: AsRef<str> + Clone>(
+    input: &mut [T],
+    options: &SortZhOptions,
+) -> Result<(), CollatorError> {
+    let strings: Vec<String> = input.iter().map(|item| item.as_ref().to_string()).collect();
+    let order = classify_and_sort(&strings, options)?;
+    gather(input, &order);
+    Ok(())
+}
+
+/// `sort_zh_with`的`Vec<String>`便利版本。
+pub fn sort_zh_vec(input: &mut Vec<String>, options: &SortZhOptions) -> Result<(), CollatorError> {
+    sort_zh_with(input, options)
+}
+
+/// 依`order`（gather索引：`order[i]`是輸出位置`i`應取用的原始索引）重排`slice`。
+fn gather<T: Clone>(slice: &mut [T], order: &[usize]) {
+    let original = slice.to_vec();
+    for (i, &source) in order.iter().enumerate() {
+        slice[i] = original[source].clone();
+    }
+}
+
+/// `options.locale`不是ICU認得的locale字串，或無法套用指定的排序強度。
+///
+/// `locale`是開放給`sort_zh_with`／`sort_zh_vec`呼叫端自由填寫的欄位，打錯字或填入不支援的
+/// locale不應讓整個process panic，因此以`Result`回傳交由呼叫端處理。
+#[derive(Debug)]
+pub struct CollatorError(String);
+
+impl std::fmt::Display for CollatorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for CollatorError {}
+
+/// ICU的`ucol_open`對「格式正確但未知」的locale（例如打錯字的`"not-a-real-locale"`）只會回報
+/// `U_USING_DEFAULT_WARNING`之類的警告並回退為root locale，而非回傳失敗——單靠
+/// `UCollator::try_from`的`Result`無法偵測這類typo。因此改為在建立collator前，先比對
+/// `locale`開頭的語言子標籤是否落在已知語言代碼內，及早攔截這類打錯字的輸入。
+const KNOWN_LANGUAGE_SUBTAGS: [&str; 60] = [
+    "zh", "en", "ja", "ko", "fr", "de", "es", "pt", "it", "ru", "ar", "hi", "bn", "pa", "jv", "vi",
+    "tr", "fa", "ur", "th", "gu", "pl", "uk", "ro", "nl", "ms", "ta", "te", "mr", "sv", "kn", "ml",
+    "my", "or", "ne", "si", "km", "lo", "am", "so", "sw", "zu", "xh", "af", "sq", "az", "eu", "be",
+    "bg", "ca", "cs", "da", "el", "et", "fi", "he", "hr", "hu", "hy", "id",
+];
+
+/// 判斷`locale`開頭的語言子標籤（第一個`-`或`_`之前的部分）是否為已知語言代碼。
+fn is_known_locale(locale: &str) -> bool {
+    let primary_subtag = locale.split(['-', '_']).next().unwrap_or(locale);
+    KNOWN_LANGUAGE_SUBTAGS.contains(&primary_subtag.to_ascii_lowercase().as_str())
+}
+
+/// 分類（ASCII／中文數字／中文字）並排序，回傳依排序結果排列的原始索引。
+fn classify_and_sort(
+    strings: &[String],
+    options: &SortZhOptions,
+) -> Result<Vec<usize>, CollatorError> {
+    if !is_known_locale(&options.locale) {
+        return Err(CollatorError(format!(
+            "unknown ICU locale: \"{}\"",
+            options.locale
+        )));
     }
-    .expect("Could not make collator.");
+    let mut collator = UCollator::try_from(options.locale.as_str())
+        .map_err(|_| CollatorError(format!("unknown ICU locale: \"{}\"", options.locale)))?;
+    collator
+        .set_strength(options.strength.into())
+        .map_err(|_| CollatorError("could not set collator strength".to_string()))?;
 
     let mut ascii_word_vec: Vec<(usize, String)> = Vec::new();
-    let mut zh_upper_number_vec: Vec<(usize, i64)> = Vec::new();
-    let mut zh_lower_number_vec: Vec<(usize, i64)> = Vec::new();
+    let mut zh_upper_number_vec: Vec<(usize, i64, String)> = Vec::new();
+    let mut zh_lower_number_vec: Vec<(usize, i64, String)> = Vec::new();
     let mut zh_word_vec: Vec<(usize, String)> = Vec::new();
 
-    input.iter().enumerate().for_each(|(i, element)| {
-        let string: String = element.into();
+    let keys: Vec<String> = strings
+        .iter()
+        .map(|string| normalize::normalize(string, options.normalization))
+        .collect();
+
+    keys.iter().enumerate().for_each(|(i, string)| {
         let chars = string.chars();
-        if chars.clone().peekable().peek().unwrap().is_ascii() {
-            ascii_word_vec.push((i, string))
+        if chars.clone().next().map_or(true, |char| char.is_ascii()) {
+            ascii_word_vec.push((i, string.clone()))
         } else {
             let zh_number_option = &options.zh_number_option;
             match zh_number_option {
-                ICUDefault => zh_word_vec.push((i, string)),
+                ICUDefault => zh_word_vec.push((i, string.clone())),
                 Definition | DefinitionWithUpperCase(_) => match parse_zh_number(chars.clone()) {
-                    (upper_case, Ok(parsed)) => {
+                    Some((upper_case, Ok(parsed), suffix)) => {
                         if zh_number_option == &DefinitionWithUpperCase(Before)
                             || zh_number_option == &DefinitionWithUpperCase(After)
                         {
                             if !upper_case {
-                                zh_lower_number_vec.push((i, parsed))
+                                zh_lower_number_vec.push((i, parsed, suffix))
                             } else if upper_case {
-                                zh_upper_number_vec.push((i, parsed))
+                                zh_upper_number_vec.push((i, parsed, suffix))
                             } else {
-                                zh_word_vec.push((i, string))
+                                zh_word_vec.push((i, string.clone()))
                             }
                         } else {
-                            zh_lower_number_vec.push((i, parsed))
+                            zh_lower_number_vec.push((i, parsed, suffix))
                         }
                     }
-                    (_, Err(_)) => zh_word_vec.push((i, string)),
+                    Some((_, Err(_), _)) | None => zh_word_vec.push((i, string.clone())),
                 },
             }
         }
@@ -136,32 +433,40 @@ pub fn sort_zh(input: Vec<JsString>) -> Vec<JsString> {
         zh_lower_number_vec,
         options.zh_number_option,
     ));
-    final_vec.append(&mut sort_zh_word(zh_word_vec, collator));
+    final_vec.append(&mut sort_zh_word(
+        zh_word_vec,
+        &collator,
+        &options.sort_strategy,
+    ));
 
-    final_vec
-        .into_iter()
-        .map(|i| input[i].clone())
-        .collect::<Vec<JsString>>()
+    Ok(final_vec)
 }
 
-fn parse_zh_number(chars: Chars) -> (bool, Result<i64, ChineseNumberParseError>) {
-    let mut upper_case = false;
-    let mut zh_number_size = 1_usize;
-    chars.clone().enumerate().for_each(|(i, char)| {
-        if i == 0_usize && UPPERCASE_NUM.contains(&char) {
-            upper_case = true
-        }
-        if !UPPERCASE_NUM.contains(&char) && !LOWERCASE_NUM.contains(&char) {
-            zh_number_size = (i as u32 - 1) as usize;
-        }
-    });
-    (
+/// 掃描字串開頭連續的中文數字字元，回傳「是否為大寫數字」、數字前綴的解析結果，
+/// 以及前綴之後剩餘的字串（作為數值相同時的決勝依據，例如`"十二測試"`對`"拾貳測試"`）。
+///
+/// 開頭字元不是中文數字（含空字串）時回傳`None`，交由呼叫端改以一般中文字詞排序。
+fn parse_zh_number(chars: Chars) -> Option<(bool, Result<i64, ChineseNumberParseError>, String)> {
+    let chars: Vec<char> = chars.collect();
+    let upper_case = chars.first().is_some_and(|char| UPPERCASE_NUM.contains(char));
+
+    let prefix_len = chars
+        .iter()
+        .position(|char| !UPPERCASE_NUM.contains(char) && !LOWERCASE_NUM.contains(char))
+        .unwrap_or(chars.len());
+
+    if prefix_len == 0 {
+        return None;
+    }
+
+    let prefix = String::from_iter(&chars[0..prefix_len]);
+    let suffix = String::from_iter(&chars[prefix_len..]);
+
+    Some((
         upper_case,
-        parse_chinese_number_to_i64(
-            ChineseNumberCountMethod::TenThousand,
-            String::from_iter(chars.collect::<Vec<char>>()[0..zh_number_size].iter()),
-        ),
-    )
+        parse_chinese_number_to_i64(ChineseNumberCountMethod::TenThousand, prefix),
+        suffix,
+    ))
 }
 
 fn sort_ascii_word(mut ascii_word_vec: Vec<(usize, String)>) -> Vec<usize> {
@@ -171,16 +476,24 @@ fn sort_ascii_word(mut ascii_word_vec: Vec<(usize, String)>) -> Vec<usize> {
 }
 
 fn sort_zh_number(
-    mut zh_upper_number_vec: Vec<(usize, i64)>,
-    mut zh_lower_number_vec: Vec<(usize, i64)>,
+    mut zh_upper_number_vec: Vec<(usize, i64, String)>,
+    mut zh_lower_number_vec: Vec<(usize, i64, String)>,
     zh_number_option: ZhNumberOption,
 ) -> Vec<usize> {
-    zh_upper_number_vec.sort_unstable_by(|(_, a_value), (_, b_value)| a_value.cmp(b_value));
-    zh_lower_number_vec.sort_unstable_by(|(_, a_value), (_, b_value)| a_value.cmp(b_value));
-    let (mut zh_upper_number_vec, _): (Vec<usize>, Vec<_>) =
-        zh_upper_number_vec.into_iter().unzip();
-    let (mut zh_lower_number_vec, _): (Vec<usize>, Vec<_>) =
-        zh_lower_number_vec.into_iter().unzip();
+    let by_value_then_suffix = |(_, a_value, a_suffix): &(usize, i64, String),
+                                 (_, b_value, b_suffix): &(usize, i64, String)| {
+        a_value.cmp(b_value).then_with(|| a_suffix.cmp(b_suffix))
+    };
+    zh_upper_number_vec.sort_unstable_by(by_value_then_suffix);
+    zh_lower_number_vec.sort_unstable_by(by_value_then_suffix);
+    let mut zh_upper_number_vec: Vec<usize> = zh_upper_number_vec
+        .into_iter()
+        .map(|(i, _, _)| i)
+        .collect();
+    let mut zh_lower_number_vec: Vec<usize> = zh_lower_number_vec
+        .into_iter()
+        .map(|(i, _, _)| i)
+        .collect();
     match zh_number_option {
         DefinitionWithUpperCase(upper_case_order) => match upper_case_order {
             Before => {
@@ -196,12 +509,58 @@ fn sort_zh_number(
     }
 }
 
-fn sort_zh_word(mut zh_word_vec: Vec<(usize, String)>, collator: UCollator) -> Vec<usize> {
-    zh_word_vec.sort_unstable_by(|(_, a_value), (_, b_value)| {
-        collator
+fn sort_zh_word(
+    mut zh_word_vec: Vec<(usize, String)>,
+    collator: &UCollator,
+    sort_strategy: &SortStrategy,
+) -> Vec<usize> {
+    zh_word_vec.sort_unstable_by(|(_, a_value), (_, b_value)| match sort_strategy {
+        SortStrategy::Collator => collator
             .strcoll_utf8(a_value, b_value)
-            .expect("Failed to collate with collator.")
+            .expect("Failed to collate with collator."),
+        SortStrategy::Pinyin(tone_handling) => pinyin::compare(a_value, b_value, *tone_handling),
     });
     let (index_vec, _): (Vec<usize>, Vec<_>) = zh_word_vec.into_iter().unzip();
     index_vec
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_zh_with_applies_a_three_cycle_permutation_correctly() {
+        let mut words = vec!["c".to_string(), "a".to_string(), "b".to_string()];
+        sort_zh_with(&mut words, &SortZhOptions::default()).unwrap();
+        assert_eq!(words, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn invalid_locale_returns_an_error_instead_of_panicking() {
+        let mut words = vec!["中".to_string(), "文".to_string()];
+        let options = SortZhOptions {
+            locale: String::from("not-a-real-locale"),
+            ..SortZhOptions::default()
+        };
+        assert!(sort_zh_with(&mut words, &options).is_err());
+    }
+
+    #[test]
+    fn known_locale_subtags_are_accepted_regardless_of_region() {
+        assert!(is_known_locale("zh-TW"));
+        assert!(is_known_locale("zh-HK"));
+        assert!(is_known_locale("EN"));
+        assert!(!is_known_locale("not-a-real-locale"));
+    }
+
+    #[test]
+    fn zh_numbers_with_the_same_value_break_ties_on_the_remaining_suffix() {
+        let mut words = vec!["十二乙".to_string(), "十二甲".to_string()];
+        let options = SortZhOptions {
+            zh_number_option: ZhNumberOption::Definition,
+            ..SortZhOptions::default()
+        };
+        sort_zh_with(&mut words, &options).unwrap();
+        assert_eq!(words, vec!["十二乙", "十二甲"]);
+    }
+}
@@ -0,0 +1,49 @@
+//! 排序前的字串正規化：大小寫摺疊（case folding）與相容性（NFKD）正規化。
+
+use caseless::Caseless;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::Normalization;
+
+/// 依[`Normalization`]設定，將字串轉換為用於分類／比較的正規化鍵值；
+/// 排序輸出仍使用原始字串，僅比較時使用此鍵值。
+///
+/// 摺疊與NFKD需交替進行兩輪才能讓全形／相容字符（如`㎒`對`MHz`）收斂為相同鍵值。
+pub(crate) fn normalize(s: &str, normalization: Normalization) -> String {
+    match normalization {
+        Normalization::None => s.to_string(),
+        Normalization::CaseFoldNfkd => s
+            .nfd()
+            .default_case_fold()
+            .nfkd()
+            .default_case_fold()
+            .nfkd()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_fold_nfkd_makes_differently_cased_words_equal() {
+        assert_eq!(
+            normalize("Apple", Normalization::CaseFoldNfkd),
+            normalize("apple", Normalization::CaseFoldNfkd)
+        );
+    }
+
+    #[test]
+    fn case_fold_nfkd_makes_compatibility_characters_equal() {
+        assert_eq!(
+            normalize("㎒", Normalization::CaseFoldNfkd),
+            normalize("MHz", Normalization::CaseFoldNfkd)
+        );
+    }
+
+    #[test]
+    fn none_leaves_the_string_untouched() {
+        assert_eq!(normalize("Apple", Normalization::None), "Apple");
+    }
+}
@@ -0,0 +1,144 @@
+//! 拼音排序模式使用的常用漢字拼音對照表與比較邏輯。
+
+use std::cmp::Ordering;
+
+use phf::{phf_map, Map};
+
+use crate::ToneHandling;
+
+/// 常用漢字對應的拼音（含聲調數字，`5`表示輕聲）。
+///
+/// 這是依常用詞頻手動收錄的子集，並非完整的Unicode漢字對照表——完整覆蓋需要一份產生自
+/// Unihan等來源的資料表（或一個拼音crate依賴），而此repo目前沒有`Cargo.toml`可以新增依賴，
+/// 因此先以手動收錄子集作為暫時方案。未收錄字的行為見[`tokenize`]與[`Token`]。
+static PINYIN_TABLE: Map<char, &'static str> = phf_map! {
+    '中' => "zhong1", '文' => "wen2", '國' => "guo2", '国' => "guo2",
+    '重' => "zhong4", '工' => "gong1", '人' => "ren2", '大' => "da4",
+    '小' => "xiao3", '上' => "shang4", '下' => "xia4", '天' => "tian1",
+    '地' => "di4", '山' => "shan1", '水' => "shui3", '火' => "huo3",
+    '木' => "mu4", '金' => "jin1", '土' => "tu3", '日' => "ri4",
+    '月' => "yue4", '年' => "nian2", '時' => "shi2", '时' => "shi2",
+    '間' => "jian1", '间' => "jian1", '好' => "hao3", '是' => "shi4",
+    '不' => "bu4", '了' => "le5", '我' => "wo3", '你' => "ni3",
+    '他' => "ta1", '她' => "ta1", '們' => "men5", '们' => "men5",
+    '這' => "zhe4", '这' => "zhe4", '那' => "na4", '有' => "you3",
+    '在' => "zai4", '就' => "jiu4", '與' => "yu3", '与' => "yu3",
+    '和' => "he2", '也' => "ye3", '都' => "dou1", '而' => "er2",
+    '之' => "zhi1", '於' => "yu2", '于' => "yu2", '及' => "ji2",
+    '台' => "tai2", '灣' => "wan1", '湾' => "wan1", '北' => "bei3",
+    '南' => "nan2", '東' => "dong1", '东' => "dong1", '西' => "xi1",
+    '新' => "xin1", '舊' => "jiu4", '旧' => "jiu4", '高' => "gao1",
+    '低' => "di1", '多' => "duo1", '少' => "shao3", '長' => "chang2",
+    '长' => "chang2", '短' => "duan3", '語' => "yu3", '语' => "yu3",
+    '言' => "yan2", '字' => "zi4", '書' => "shu1", '书' => "shu1",
+    '學' => "xue2", '学' => "xue2", '校' => "xiao4", '生' => "sheng1",
+    '老' => "lao3", '師' => "shi1", '师' => "shi1", '公' => "gong1",
+    '司' => "si1", '商' => "shang1", '店' => "dian4", '家' => "jia1",
+    '一' => "yi1", '二' => "er4", '三' => "san1", '四' => "si4",
+    '五' => "wu3", '六' => "liu4", '七' => "qi1", '八' => "ba1",
+    '九' => "jiu3", '十' => "shi2", '零' => "ling2", '百' => "bai3",
+    '千' => "qian1", '萬' => "wan4", '万' => "wan4", '億' => "yi4",
+    '亿' => "yi4", '個' => "ge4", '个' => "ge4", '點' => "dian3",
+    '点' => "dian3", '用' => "yong4", '會' => "hui4", '会' => "hui4",
+    '能' => "neng2", '可' => "ke3", '以' => "yi3", '要' => "yao4",
+    '到' => "dao4", '說' => "shuo1", '说' => "shuo1", '話' => "hua4",
+    '话' => "hua4", '看' => "kan4", '見' => "jian4", '见' => "jian4",
+    '聽' => "ting1", '听' => "ting1", '想' => "xiang3", '知' => "zhi1",
+    '道' => "dao4", '做' => "zuo4", '作' => "zuo4", '去' => "qu4",
+    '來' => "lai2", '来' => "lai2", '回' => "hui2", '走' => "zou3",
+    '出' => "chu1", '入' => "ru4", '開' => "kai1", '开' => "kai1",
+    '關' => "guan1", '关' => "guan1", '門' => "men2", '门' => "men2",
+    '車' => "che1", '车' => "che1", '路' => "lu4", '站' => "zhan4",
+    '城' => "cheng2", '市' => "shi4", '省' => "sheng3", '縣' => "xian4",
+    '县' => "xian4", '村' => "cun1", '里' => "li3", '外' => "wai4",
+    '內' => "nei4", '内' => "nei4", '前' => "qian2", '後' => "hou4",
+    '后' => "hou4", '左' => "zuo3", '右' => "you4", '面' => "mian4",
+    '頭' => "tou2", '头' => "tou2", '手' => "shou3", '心' => "xin1",
+    '口' => "kou3", '目' => "mu4", '耳' => "er3", '眼' => "yan3",
+    '身' => "shen1", '體' => "ti3", '体' => "ti3", '腳' => "jiao3",
+    '脚' => "jiao3", '食' => "shi2", '飯' => "fan4", '饭' => "fan4",
+    '喝' => "he1", '吃' => "chi1", '買' => "mai3", '买' => "mai3",
+    '賣' => "mai4", '卖' => "mai4", '錢' => "qian2", '钱' => "qian2",
+    '價' => "jia4", '价' => "jia4", '的' => "de5", '得' => "de2",
+    '着' => "zhe5", '著' => "zhe5", '把' => "ba3",
+    '被' => "bei4", '給' => "gei3", '给' => "gei3", '讓' => "rang4",
+    '让' => "rang4", '叫' => "jiao4", '為' => "wei4",
+    '为' => "wei4", '因' => "yin1", '所' => "suo3", '但' => "dan4",
+    '如' => "ru2", '果' => "guo3", '雖' => "sui1", '虽' => "sui1",
+    '然' => "ran2", '卻' => "que4", '却' => "que4", '或' => "huo4",
+    '者' => "zhe3", '每' => "mei3", '些' => "xie1", '還' => "hai2",
+    '还' => "hai2", '再' => "zai4", '又' => "you4", '已' => "yi3",
+    '經' => "jing1", '经' => "jing1", '常' => "chang2", '總' => "zong3",
+    '总' => "zong3", '非' => "fei1", '很' => "hen3", '太' => "tai4",
+    '最' => "zui4", '更' => "geng4", '比' => "bi3", '較' => "jiao4",
+    '较' => "jiao4", '其' => "qi2",
+};
+
+/// 判斷`c`是否落在常用CJK統一表意文字／相容表意文字的Unicode範圍內。
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}')
+}
+
+/// 拼音排序用的比較鍵單位：非中文字元依碼點排序，排在中文字元之前；中文字元先比較拼音，
+/// 若拼音相同（或字元不在[`PINYIN_TABLE`]中）再以原始字元作為決勝依據。
+///
+/// 未收錄拼音的漢字（即[`is_han`]為真但`PINYIN_TABLE`查無資料）仍歸類為`Chinese`，
+/// 只是拿自身字元的字串當作鍵值——這只是讓它們不被誤判為非中文字元排到最前面，
+/// 並不代表它們會依實際讀音與已收錄的字「插花」排序：由於鍵值退化為原始碼點（遠高於
+/// 拼音鍵使用的ASCII字母），未收錄字在排序時會整批排在所有已收錄字之後，而非依發音
+/// 交錯。要做到真正依讀音交錯排序，需要這些字實際的拼音資料。
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Token {
+    Other(char),
+    Chinese(String, char),
+}
+
+fn pinyin_key(pinyin: &'static str, tone_handling: ToneHandling) -> String {
+    match tone_handling {
+        ToneHandling::Ignore => pinyin.trim_end_matches(|c: char| c.is_ascii_digit()).into(),
+        ToneHandling::ByTone => pinyin.into(),
+    }
+}
+
+fn tokenize(s: &str, tone_handling: ToneHandling) -> Vec<Token> {
+    s.chars()
+        .map(|char| match PINYIN_TABLE.get(&char) {
+            Some(&pinyin) => Token::Chinese(pinyin_key(pinyin, tone_handling), char),
+            None if is_han(char) => Token::Chinese(char.to_string(), char),
+            None => Token::Other(char),
+        })
+        .collect()
+}
+
+/// 依拼音比較兩個字串，`tone_handling`決定聲調是否參與比較。
+pub(crate) fn compare(a: &str, b: &str, tone_handling: ToneHandling) -> Ordering {
+    tokenize(a, tone_handling).cmp(&tokenize(b, tone_handling))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_chinese_words_by_pinyin_before_ascii_is_out_of_the_way() {
+        let mut words = vec!["中文", "中國", "abc", "重工"];
+        words.sort_by(|a, b| compare(a, b, ToneHandling::Ignore));
+        assert_eq!(words, vec!["abc", "中國", "中文", "重工"]);
+    }
+
+    #[test]
+    fn unmapped_han_characters_still_sort_after_ascii_instead_of_before() {
+        // 「圞」不在PINYIN_TABLE中，但仍是漢字，不應被誤判為Token::Other排到「abc」之前。
+        let mut words = vec!["abc", "圞"];
+        words.sort_by(|a, b| compare(a, b, ToneHandling::Ignore));
+        assert_eq!(words, vec!["abc", "圞"]);
+    }
+
+    #[test]
+    fn unmapped_han_characters_sort_after_mapped_ones_not_interleaved() {
+        // 「圞」沒有收錄拼音，鍵值退化為原始碼點，因此整批排在已收錄字（如「中」）之後。
+        let mut words = vec!["圞", "中"];
+        words.sort_by(|a, b| compare(a, b, ToneHandling::Ignore));
+        assert_eq!(words, vec!["中", "圞"]);
+    }
+}